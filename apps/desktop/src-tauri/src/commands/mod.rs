@@ -0,0 +1,9 @@
+// ═══════════════════════════════════════════════════════════════════════════════
+// Commands
+// ═══════════════════════════════════════════════════════════════════════════════
+
+pub mod shortcuts;
+pub mod system;
+pub mod tray;
+pub mod updater;
+pub mod window;
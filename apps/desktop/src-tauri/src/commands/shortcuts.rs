@@ -0,0 +1,113 @@
+// ═══════════════════════════════════════════════════════════════════════════════
+// Global Shortcut Commands
+// ═══════════════════════════════════════════════════════════════════════════════
+
+use std::collections::HashMap;
+
+use tauri::AppHandle;
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
+use tauri_plugin_store::StoreExt;
+
+use crate::events::{AppEmitter, AppEvent};
+
+const STORE_FILE: &str = "config.json";
+const CONFIG_KEY: &str = "shortcuts";
+
+/// Canonical accelerator string (the parsed `Shortcut`'s `to_string()`, not
+/// the text passed to [`register_shortcut`]) to the frontend-defined action
+/// it should trigger. The frontend decides what each action does;
+/// the backend only owns registration and dispatch.
+pub type Bindings = HashMap<String, String>;
+
+fn load_bindings(app: &AppHandle) -> Bindings {
+    app.store(STORE_FILE)
+        .ok()
+        .and_then(|store| store.get(CONFIG_KEY))
+        .and_then(|value| serde_json::from_value(value).ok())
+        .unwrap_or_default()
+}
+
+fn save_bindings(app: &AppHandle, bindings: &Bindings) -> Result<(), String> {
+    let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
+    store.set(
+        CONFIG_KEY,
+        serde_json::to_value(bindings).map_err(|e| e.to_string())?,
+    );
+    store.save().map_err(|e| e.to_string())
+}
+
+fn parse_accelerator(accelerator: &str) -> Result<Shortcut, String> {
+    accelerator
+        .parse::<Shortcut>()
+        .map_err(|e| format!("invalid accelerator `{accelerator}`: {e}"))
+}
+
+/// Binds `accelerator` to `action_id` and registers it with the OS.
+/// Rejects accelerators that are already bound rather than silently
+/// overwriting the previous binding.
+///
+/// Bindings are keyed by the parsed `Shortcut`'s canonical string, not the
+/// caller's raw text, since that's also what [`handle_triggered`] looks
+/// events up by.
+#[tauri::command]
+pub fn register_shortcut(
+    app: AppHandle,
+    accelerator: String,
+    action_id: String,
+) -> Result<(), String> {
+    let shortcut = parse_accelerator(&accelerator)?;
+    let canonical = shortcut.to_string();
+    let mut bindings = load_bindings(&app);
+    if bindings.contains_key(&canonical) {
+        return Err(format!("accelerator `{accelerator}` is already bound"));
+    }
+
+    app.global_shortcut()
+        .register(shortcut)
+        .map_err(|e| format!("failed to register `{accelerator}`: {e}"))?;
+
+    bindings.insert(canonical, action_id);
+    save_bindings(&app, &bindings)
+}
+
+#[tauri::command]
+pub fn unregister_shortcut(app: AppHandle, accelerator: String) -> Result<(), String> {
+    let shortcut = parse_accelerator(&accelerator)?;
+    app.global_shortcut()
+        .unregister(shortcut.clone())
+        .map_err(|e| e.to_string())?;
+
+    let mut bindings = load_bindings(&app);
+    bindings.remove(&shortcut.to_string());
+    save_bindings(&app, &bindings)
+}
+
+#[tauri::command]
+pub fn list_shortcuts(app: AppHandle) -> Bindings {
+    load_bindings(&app)
+}
+
+/// Re-registers every saved binding with the OS. Called once from
+/// `.setup()` since registrations do not survive a restart.
+pub fn register_saved(app: &AppHandle) {
+    for accelerator in load_bindings(app).keys() {
+        if let Ok(shortcut) = parse_accelerator(accelerator) {
+            let _ = app.global_shortcut().register(shortcut);
+        }
+    }
+}
+
+/// Invoked by the global-shortcut plugin's handler whenever any registered
+/// accelerator fires. Looks up the bound action and emits
+/// `shortcut-triggered` so the frontend decides what to do.
+pub fn handle_triggered(app: &AppHandle, shortcut: &Shortcut, state: ShortcutState) {
+    if state != ShortcutState::Pressed {
+        return;
+    }
+    let accelerator = shortcut.to_string();
+    if let Some(action_id) = load_bindings(app).get(&accelerator) {
+        AppEmitter::new(app).emit(AppEvent::ShortcutTriggered {
+            action_id: action_id.clone(),
+        });
+    }
+}
@@ -3,6 +3,8 @@
 // ═══════════════════════════════════════════════════════════════════════════════
 
 use serde::Serialize;
+use sysinfo::System;
+use tauri::{AppHandle, Manager};
 
 #[derive(Serialize)]
 pub struct AppInfo {
@@ -17,3 +19,45 @@ pub fn get_app_info() -> AppInfo {
         version: env!("CARGO_PKG_VERSION").to_string(),
     }
 }
+
+/// Environment snapshot for support/debugging, analogous to a CLI `info`
+/// subcommand. Meant to be attached verbatim to bug reports.
+#[derive(Serialize)]
+pub struct SystemInfo {
+    os_name: String,
+    os_version: String,
+    arch: String,
+    total_memory_bytes: u64,
+    available_memory_bytes: u64,
+    cpu_count: usize,
+    webview_version: Option<String>,
+    install_dir: Option<String>,
+    data_dir: Option<String>,
+}
+
+#[tauri::command]
+pub fn get_system_info(app: AppHandle) -> SystemInfo {
+    let mut sys = System::new();
+    sys.refresh_memory();
+    sys.refresh_cpu_list(sysinfo::CpuRefreshKind::everything());
+
+    let resolver = app.path();
+
+    SystemInfo {
+        os_name: System::name().unwrap_or_else(|| "unknown".to_string()),
+        os_version: System::os_version().unwrap_or_else(|| "unknown".to_string()),
+        arch: std::env::consts::ARCH.to_string(),
+        total_memory_bytes: sys.total_memory(),
+        available_memory_bytes: sys.available_memory(),
+        cpu_count: sys.cpus().len(),
+        webview_version: tauri::webview_version().ok(),
+        install_dir: resolver
+            .resource_dir()
+            .ok()
+            .map(|p| p.to_string_lossy().into_owned()),
+        data_dir: resolver
+            .app_data_dir()
+            .ok()
+            .map(|p| p.to_string_lossy().into_owned()),
+    }
+}
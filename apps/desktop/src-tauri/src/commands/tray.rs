@@ -0,0 +1,73 @@
+// ═══════════════════════════════════════════════════════════════════════════════
+// Tray Commands
+// ═══════════════════════════════════════════════════════════════════════════════
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+use tauri_plugin_store::StoreExt;
+
+use crate::tray::TRAY_ID;
+
+const STORE_FILE: &str = "config.json";
+const CONFIG_KEY: &str = "tray";
+
+/// Persisted tray behavior. `minimize_to_tray` decides whether closing the
+/// main window hides it instead of exiting the app.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TraySettings {
+    pub minimize_to_tray: bool,
+}
+
+impl Default for TraySettings {
+    fn default() -> Self {
+        Self {
+            minimize_to_tray: false,
+        }
+    }
+}
+
+pub fn load_settings(app: &AppHandle) -> TraySettings {
+    app.store(STORE_FILE)
+        .ok()
+        .and_then(|store| store.get(CONFIG_KEY))
+        .and_then(|value| serde_json::from_value(value).ok())
+        .unwrap_or_default()
+}
+
+fn save_settings(app: &AppHandle, settings: &TraySettings) -> Result<(), String> {
+    let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
+    store.set(
+        CONFIG_KEY,
+        serde_json::to_value(settings).map_err(|e| e.to_string())?,
+    );
+    store.save().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn get_tray_settings(app: AppHandle) -> TraySettings {
+    load_settings(&app)
+}
+
+#[tauri::command]
+pub fn set_tray_settings(app: AppHandle, settings: TraySettings) -> Result<(), String> {
+    save_settings(&app, &settings)
+}
+
+#[tauri::command]
+pub fn set_tray_tooltip(app: AppHandle, tooltip: String) -> Result<(), String> {
+    app.tray_by_id(TRAY_ID)
+        .ok_or_else(|| "tray icon not initialized".to_string())?
+        .set_tooltip(Some(tooltip))
+        .map_err(|e| e.to_string())
+}
+
+/// Sets the tray's badge/title text (shown next to the icon on platforms
+/// that support it), letting the UI surface background state such as an
+/// unread count. Pass `None` to clear it.
+#[tauri::command]
+pub fn set_tray_badge(app: AppHandle, title: Option<String>) -> Result<(), String> {
+    app.tray_by_id(TRAY_ID)
+        .ok_or_else(|| "tray icon not initialized".to_string())?
+        .set_title(title)
+        .map_err(|e| e.to_string())
+}
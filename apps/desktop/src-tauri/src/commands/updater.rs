@@ -0,0 +1,162 @@
+// ═══════════════════════════════════════════════════════════════════════════════
+// Updater Commands
+// ═══════════════════════════════════════════════════════════════════════════════
+
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+use tauri_plugin_store::StoreExt;
+use tauri_plugin_updater::UpdaterExt;
+
+use crate::events::{AppEmitter, AppEvent};
+
+const STORE_FILE: &str = "config.json";
+const CONFIG_KEY: &str = "updater";
+
+/// Persisted updater preferences, stored alongside the rest of the app
+/// config via the store plugin.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdaterConfig {
+    /// Whether to silently check for updates shortly after launch.
+    pub check_on_startup: bool,
+    /// Overrides the endpoint baked into `tauri.conf.json`, if set.
+    pub pinned_endpoint: Option<String>,
+}
+
+impl Default for UpdaterConfig {
+    fn default() -> Self {
+        Self {
+            check_on_startup: true,
+            pinned_endpoint: None,
+        }
+    }
+}
+
+fn load_config(app: &AppHandle) -> UpdaterConfig {
+    app.store(STORE_FILE)
+        .ok()
+        .and_then(|store| store.get(CONFIG_KEY))
+        .and_then(|value| serde_json::from_value(value).ok())
+        .unwrap_or_default()
+}
+
+fn save_config(app: &AppHandle, config: &UpdaterConfig) -> Result<(), String> {
+    let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
+    store.set(CONFIG_KEY, serde_json::to_value(config).map_err(|e| e.to_string())?);
+    store.save().map_err(|e| e.to_string())
+}
+
+/// Summary of an available update, sent to the frontend in both the
+/// `update-available` event and the return value of [`check_for_update`].
+#[derive(Debug, Clone, Serialize)]
+pub struct UpdateInfo {
+    pub version: String,
+    pub notes: Option<String>,
+    pub date: Option<String>,
+}
+
+async fn fetch_update(
+    app: &AppHandle,
+) -> Result<Option<tauri_plugin_updater::Update>, String> {
+    let config = load_config(app);
+    let mut builder = app.updater_builder();
+    if let Some(endpoint) = config.pinned_endpoint {
+        let url = endpoint.parse().map_err(|e| format!("invalid pinned endpoint: {e}"))?;
+        builder = builder.endpoints(vec![url]).map_err(|e| e.to_string())?;
+    }
+    builder
+        .build()
+        .map_err(|e| e.to_string())?
+        .check()
+        .await
+        .map_err(|e| e.to_string())
+}
+
+fn update_info(update: &tauri_plugin_updater::Update) -> UpdateInfo {
+    UpdateInfo {
+        version: update.version.clone(),
+        notes: update.body.clone(),
+        date: update.date.map(|d| d.to_string()),
+    }
+}
+
+/// Checks the configured endpoint for a newer release. Emits
+/// `update-available` when one is found so any listening window updates
+/// without having to poll.
+#[tauri::command]
+pub async fn check_for_update(app: AppHandle) -> Result<Option<UpdateInfo>, String> {
+    let update = fetch_update(&app).await?;
+    let info = update.as_ref().map(update_info);
+    if let Some(info) = &info {
+        AppEmitter::new(&app).emit(AppEvent::UpdateAvailable {
+            version: info.version.clone(),
+            notes: info.notes.clone(),
+            date: info.date.clone(),
+        });
+    }
+    Ok(info)
+}
+
+/// Downloads and installs the pending update, reporting progress via
+/// `update-progress` events and a final `update-finished` event. Does not
+/// restart the app; call [`relaunch`] once the frontend is ready.
+#[tauri::command]
+pub async fn download_and_install(app: AppHandle) -> Result<(), String> {
+    let update = fetch_update(&app)
+        .await?
+        .ok_or_else(|| "no update is available".to_string())?;
+
+    let progress_handle = app.clone();
+    let mut downloaded: u64 = 0;
+    update
+        .download_and_install(
+            move |chunk_length, content_length| {
+                downloaded += chunk_length as u64;
+                AppEmitter::new(&progress_handle).emit(AppEvent::UpdateProgress {
+                    downloaded,
+                    content_length,
+                });
+            },
+            || {},
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+
+    AppEmitter::new(&app).emit(AppEvent::UpdateFinished);
+    Ok(())
+}
+
+/// Restarts the app, completing the install once an update has been
+/// downloaded.
+#[tauri::command]
+pub fn relaunch(app: AppHandle) {
+    app.restart();
+}
+
+#[tauri::command]
+pub fn get_updater_config(app: AppHandle) -> UpdaterConfig {
+    load_config(&app)
+}
+
+#[tauri::command]
+pub fn set_updater_config(app: AppHandle, config: UpdaterConfig) -> Result<(), String> {
+    save_config(&app, &config)
+}
+
+/// Kicked off from `.setup()`: if the user hasn't opted out, silently
+/// checks for an update and emits `update-available` on a hit. Runs on a
+/// background task so it never blocks startup.
+pub fn spawn_startup_check(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        if !load_config(&app).check_on_startup {
+            return;
+        }
+        if let Ok(Some(update)) = fetch_update(&app).await {
+            let info = update_info(&update);
+            AppEmitter::new(&app).emit(AppEvent::UpdateAvailable {
+                version: info.version,
+                notes: info.notes,
+                date: info.date,
+            });
+        }
+    });
+}
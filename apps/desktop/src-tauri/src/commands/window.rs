@@ -0,0 +1,115 @@
+// ═══════════════════════════════════════════════════════════════════════════════
+// Window Commands
+// ═══════════════════════════════════════════════════════════════════════════════
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager, PhysicalPosition, PhysicalSize, WebviewWindow};
+use tauri_plugin_store::StoreExt;
+
+const STORE_FILE: &str = "config.json";
+const CONFIG_KEY: &str = "window";
+
+/// Persisted window chrome and geometry, applied to the main window during
+/// `.setup()`. Size and position are saved when the main window is asked to
+/// close and whenever [`save_window_state`] is invoked.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WindowConfig {
+    pub width: f64,
+    pub height: f64,
+    pub min_width: Option<f64>,
+    pub min_height: Option<f64>,
+    pub max_width: Option<f64>,
+    pub max_height: Option<f64>,
+    pub resizable: bool,
+    pub fullscreen: bool,
+    pub always_on_top: bool,
+    pub decorations: bool,
+    pub position: Option<(f64, f64)>,
+}
+
+impl Default for WindowConfig {
+    fn default() -> Self {
+        Self {
+            width: 1024.0,
+            height: 768.0,
+            min_width: None,
+            min_height: None,
+            max_width: None,
+            max_height: None,
+            resizable: true,
+            fullscreen: false,
+            always_on_top: false,
+            decorations: true,
+            position: None,
+        }
+    }
+}
+
+pub fn load_config(app: &AppHandle) -> WindowConfig {
+    app.store(STORE_FILE)
+        .ok()
+        .and_then(|store| store.get(CONFIG_KEY))
+        .and_then(|value| serde_json::from_value(value).ok())
+        .unwrap_or_default()
+}
+
+fn save_config(app: &AppHandle, config: &WindowConfig) -> Result<(), String> {
+    let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
+    store.set(CONFIG_KEY, serde_json::to_value(config).map_err(|e| e.to_string())?);
+    store.save().map_err(|e| e.to_string())
+}
+
+/// Applies a [`WindowConfig`] to the main window. Called from `.setup()`,
+/// before the window is shown, and by [`restore_window_state`].
+pub fn apply_config(window: &WebviewWindow, config: &WindowConfig) {
+    let _ = window.set_size(PhysicalSize::new(config.width, config.height));
+    let _ = window.set_resizable(config.resizable);
+    let _ = window.set_fullscreen(config.fullscreen);
+    let _ = window.set_always_on_top(config.always_on_top);
+    let _ = window.set_decorations(config.decorations);
+
+    if let (Some(min_w), Some(min_h)) = (config.min_width, config.min_height) {
+        let _ = window.set_min_size(Some(PhysicalSize::new(min_w, min_h)));
+    }
+    if let (Some(max_w), Some(max_h)) = (config.max_width, config.max_height) {
+        let _ = window.set_max_size(Some(PhysicalSize::new(max_w, max_h)));
+    }
+    if let Some((x, y)) = config.position {
+        let _ = window.set_position(PhysicalPosition::new(x, y));
+    }
+}
+
+#[tauri::command]
+pub fn set_always_on_top(window: WebviewWindow, enabled: bool) -> Result<(), String> {
+    window.set_always_on_top(enabled).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn toggle_fullscreen(window: WebviewWindow) -> Result<bool, String> {
+    let is_fullscreen = window.is_fullscreen().map_err(|e| e.to_string())?;
+    window
+        .set_fullscreen(!is_fullscreen)
+        .map_err(|e| e.to_string())?;
+    Ok(!is_fullscreen)
+}
+
+/// Snapshots the window's current size and position into the store so
+/// [`restore_window_state`] (or the next startup) can bring it back.
+#[tauri::command]
+pub fn save_window_state(app: AppHandle, window: WebviewWindow) -> Result<(), String> {
+    let mut config = load_config(&app);
+    let size = window.inner_size().map_err(|e| e.to_string())?;
+    let position = window.outer_position().map_err(|e| e.to_string())?;
+
+    config.width = size.width as f64;
+    config.height = size.height as f64;
+    config.position = Some((position.x as f64, position.y as f64));
+
+    save_config(&app, &config)
+}
+
+#[tauri::command]
+pub fn restore_window_state(app: AppHandle, window: WebviewWindow) -> Result<(), String> {
+    apply_config(&window, &load_config(&app));
+    Ok(())
+}
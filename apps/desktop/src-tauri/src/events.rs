@@ -0,0 +1,112 @@
+// ═══════════════════════════════════════════════════════════════════════════════
+// Event Bridge
+// ═══════════════════════════════════════════════════════════════════════════════
+//
+// Rust⇒frontend half of the IPC story. Commands remain the request/response
+// side; this module lets long-running backend work (update checks, file
+// watchers, shortcut handlers, ...) push state to the UI without blocking an
+// `invoke`.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+
+use serde::Serialize;
+use serde_json::Value;
+use tauri::{AppHandle, Emitter};
+
+/// Strongly-typed events the backend can push to the frontend. Each variant
+/// carries its own serde payload and maps to a stable channel name that the
+/// frontend subscribes to by string.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum AppEvent {
+    UpdateAvailable {
+        version: String,
+        notes: Option<String>,
+        date: Option<String>,
+    },
+    UpdateProgress {
+        downloaded: u64,
+        content_length: Option<u64>,
+    },
+    UpdateFinished,
+    ShortcutTriggered {
+        action_id: String,
+    },
+}
+
+impl AppEvent {
+    /// The channel this event is emitted on, used both for the window
+    /// `emit` call and for matching against subscriptions.
+    pub fn channel(&self) -> &'static str {
+        match self {
+            AppEvent::UpdateAvailable { .. } => "update-available",
+            AppEvent::UpdateProgress { .. } => "update-progress",
+            AppEvent::UpdateFinished => "update-finished",
+            AppEvent::ShortcutTriggered { .. } => "shortcut-triggered",
+        }
+    }
+}
+
+/// Channels the frontend has expressed interest in via [`subscribe`], plus
+/// the last payload emitted on each channel regardless of subscription
+/// state. The latter lets a late subscriber catch up on e.g. a startup
+/// update check that completed before the frontend had a chance to
+/// subscribe, instead of missing it entirely.
+#[derive(Default)]
+pub struct Subscriptions {
+    channels: Mutex<HashSet<String>>,
+    last_emitted: Mutex<HashMap<String, Value>>,
+}
+
+/// Thin wrapper around an [`AppHandle`] that emits [`AppEvent`]s gated by
+/// the current subscription set.
+pub struct AppEmitter<'a> {
+    app: &'a AppHandle,
+}
+
+impl<'a> AppEmitter<'a> {
+    pub fn new(app: &'a AppHandle) -> Self {
+        Self { app }
+    }
+
+    /// Records `event` as the latest on its channel, then emits it to the
+    /// window if, and only if, the frontend has subscribed to that
+    /// channel. Swallows emit errors since a closed window is not
+    /// actionable here.
+    pub fn emit(&self, event: AppEvent) {
+        let Some(subs) = self.app.try_state::<Subscriptions>() else {
+            return;
+        };
+        let channel = event.channel();
+        let payload = serde_json::to_value(&event).unwrap_or(Value::Null);
+        subs.last_emitted
+            .lock()
+            .unwrap()
+            .insert(channel.to_string(), payload.clone());
+
+        if subs.channels.lock().unwrap().contains(channel) {
+            let _ = self.app.emit(channel, payload);
+        }
+    }
+}
+
+#[tauri::command]
+pub fn subscribe(app: AppHandle, channel: String) {
+    let Some(subs) = app.try_state::<Subscriptions>() else {
+        return;
+    };
+    subs.channels.lock().unwrap().insert(channel.clone());
+
+    let replay = subs.last_emitted.lock().unwrap().get(&channel).cloned();
+    if let Some(payload) = replay {
+        let _ = app.emit(&channel, payload);
+    }
+}
+
+#[tauri::command]
+pub fn unsubscribe(app: AppHandle, channel: String) {
+    if let Some(subs) = app.try_state::<Subscriptions>() {
+        subs.channels.lock().unwrap().remove(&channel);
+    }
+}
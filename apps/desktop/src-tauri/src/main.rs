@@ -0,0 +1,77 @@
+// ═══════════════════════════════════════════════════════════════════════════════
+// NYOWORKS Desktop - Main Entry
+// ═══════════════════════════════════════════════════════════════════════════════
+
+#![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
+
+mod commands;
+mod events;
+mod tray;
+
+use tauri::{Manager, WindowEvent};
+
+fn main() {
+    tauri::Builder::default()
+        .plugin(tauri_plugin_shell::init())
+        .plugin(tauri_plugin_store::Builder::default().build())
+        .plugin(tauri_plugin_updater::Builder::default().build())
+        .plugin(
+            tauri_plugin_global_shortcut::Builder::new()
+                .with_handler(|app, shortcut, event| {
+                    commands::shortcuts::handle_triggered(app, shortcut, event.state());
+                })
+                .build(),
+        )
+        .manage(events::Subscriptions::default())
+        .invoke_handler(tauri::generate_handler![
+            commands::system::get_app_info,
+            commands::system::get_system_info,
+            commands::updater::check_for_update,
+            commands::updater::download_and_install,
+            commands::updater::relaunch,
+            commands::updater::get_updater_config,
+            commands::updater::set_updater_config,
+            commands::window::set_always_on_top,
+            commands::window::toggle_fullscreen,
+            commands::window::save_window_state,
+            commands::window::restore_window_state,
+            commands::tray::get_tray_settings,
+            commands::tray::set_tray_settings,
+            commands::tray::set_tray_tooltip,
+            commands::tray::set_tray_badge,
+            commands::shortcuts::register_shortcut,
+            commands::shortcuts::unregister_shortcut,
+            commands::shortcuts::list_shortcuts,
+            events::subscribe,
+            events::unsubscribe,
+        ])
+        .setup(|app| {
+            let window = app.get_webview_window("main").unwrap();
+            commands::window::apply_config(&window, &commands::window::load_config(&app.handle()));
+            let _ = window.show();
+
+            tray::build(&app.handle())?;
+
+            let close_handle = app.handle().clone();
+            window.on_window_event(move |event| {
+                if let WindowEvent::CloseRequested { api, .. } = event {
+                    let Some(window) = close_handle.get_webview_window("main") else {
+                        return;
+                    };
+                    let _ = commands::window::save_window_state(close_handle.clone(), window.clone());
+
+                    if commands::tray::load_settings(&close_handle).minimize_to_tray {
+                        api.prevent_close();
+                        let _ = window.hide();
+                    }
+                }
+            });
+
+            commands::updater::spawn_startup_check(app.handle().clone());
+            commands::shortcuts::register_saved(&app.handle());
+
+            Ok(())
+        })
+        .run(tauri::generate_context!())
+        .expect("error while running tauri application");
+}
@@ -0,0 +1,70 @@
+// ═══════════════════════════════════════════════════════════════════════════════
+// System Tray
+// ═══════════════════════════════════════════════════════════════════════════════
+//
+// Builds the tray icon and menu, and toggles main-window visibility so
+// NYOWORKS can run as a resident background app instead of dying on close.
+
+use tauri::{
+    menu::{Menu, MenuItem},
+    tray::{MouseButton, MouseButtonState, TrayIconBuilder, TrayIconEvent},
+    AppHandle, Manager,
+};
+
+pub const TRAY_ID: &str = "main";
+
+pub fn build(app: &AppHandle) -> tauri::Result<()> {
+    let show_hide = MenuItem::with_id(app, "show_hide", "Show/Hide", true, None::<&str>)?;
+    let check_updates =
+        MenuItem::with_id(app, "check_updates", "Check for Updates", true, None::<&str>)?;
+    let quit = MenuItem::with_id(app, "quit", "Quit", true, None::<&str>)?;
+    let menu = Menu::with_items(app, &[&show_hide, &check_updates, &quit])?;
+
+    let mut builder = TrayIconBuilder::with_id(TRAY_ID)
+        .menu(&menu)
+        .tooltip("NYOWORKS")
+        .on_menu_event(|app, event| match event.id.as_ref() {
+            "show_hide" => toggle_main_window(app),
+            "check_updates" => {
+                let app = app.clone();
+                tauri::async_runtime::spawn(async move {
+                    let _ = crate::commands::updater::check_for_update(app).await;
+                });
+            }
+            "quit" => app.exit(0),
+            _ => {}
+        })
+        .on_tray_icon_event(|tray, event| {
+            if let TrayIconEvent::Click {
+                button: MouseButton::Left,
+                button_state: MouseButtonState::Up,
+                ..
+            } = event
+            {
+                toggle_main_window(tray.app_handle());
+            }
+        });
+
+    // Not every window config ships a default icon (and some platforms
+    // don't surface one); fall back to whatever the tray builder defaults
+    // to rather than panicking on `unwrap()`.
+    if let Some(icon) = app.default_window_icon().cloned() {
+        builder = builder.icon(icon);
+    }
+
+    builder.build(app)?;
+
+    Ok(())
+}
+
+fn toggle_main_window(app: &AppHandle) {
+    let Some(window) = app.get_webview_window("main") else {
+        return;
+    };
+    if window.is_visible().unwrap_or(false) {
+        let _ = window.hide();
+    } else {
+        let _ = window.show();
+        let _ = window.set_focus();
+    }
+}